@@ -31,12 +31,38 @@ pub async fn execute_script(
   let factory = CliFactory::from_flags(flags).await?;
   let cli_options = factory.cli_options();
   let tasks_config = cli_options.resolve_tasks_config()?;
+  let task_aliases = cli_options.resolve_task_aliases()?;
   let maybe_package_json = cli_options.maybe_package_json();
   let package_json_scripts = maybe_package_json
     .as_ref()
     .and_then(|p| p.scripts.clone())
     .unwrap_or_default();
 
+  if task_flags.is_info {
+    let task = match task_flags.task.as_deref() {
+      Some(task_name) => {
+        let (resolved_task_name, alias_args) =
+          match resolve_task_alias(task_name, &task_aliases, &tasks_config)? {
+            Some((real_task_name, preset_args)) => {
+              (real_task_name, preset_args)
+            }
+            None => (task_name.to_string(), String::new()),
+          };
+        Some((resolved_task_name, alias_args))
+      }
+      None => None,
+    };
+    return print_task_info(
+      &factory,
+      cli_options,
+      &tasks_config,
+      &package_json_scripts,
+      task,
+      task_flags.cwd.as_deref(),
+    )
+    .await;
+  }
+
   let task_name = match &task_flags.task {
     Some(task) => task,
     None => {
@@ -44,6 +70,12 @@ pub async fn execute_script(
       return Ok(1);
     }
   };
+  let (task_name, alias_args) =
+    match resolve_task_alias(task_name, &task_aliases, &tasks_config)? {
+      Some((real_task_name, preset_args)) => (real_task_name, preset_args),
+      None => (task_name.clone(), String::new()),
+    };
+  let task_name = &task_name;
 
   if let Some(script) = tasks_config.get(task_name) {
     let config_file_url = cli_options.maybe_config_file_specifier().unwrap();
@@ -56,15 +88,50 @@ pub async fn execute_script(
       Some(path) => canonicalize_path(&PathBuf::from(path))?,
       None => config_file_path.parent().unwrap().to_owned(),
     };
-    let script = get_script_with_args(script, cli_options);
-    output_task(task_name, &script);
-    let seq_list = deno_task_shell::parser::parse(&script)
-      .with_context(|| format!("Error parsing script '{task_name}'."))?;
-    let env_vars = collect_env_vars();
+    let script = if alias_args.is_empty() {
+      script.to_string()
+    } else {
+      format!("{script} {alias_args}")
+    };
+    let entry_script = get_script_with_args(&script, cli_options);
+
+    let task_dependencies = cli_options.resolve_task_dependencies()?;
+    let execution_order = resolve_task_execution_order(
+      task_name,
+      &tasks_config,
+      &task_dependencies,
+    )?;
+
     let local = LocalSet::new();
-    let future =
-      deno_task_shell::execute(seq_list, env_vars, &cwd, Default::default());
-    let exit_code = local.run_until(future).await;
+    let exit_code = local
+      .run_until(async {
+        let levels = if task_flags.parallel {
+          group_into_levels(&execution_order, &task_dependencies)
+        } else {
+          execution_order.iter().map(|name| vec![name.clone()]).collect()
+        };
+        for level in levels {
+          let results = futures::future::join_all(level.iter().map(
+            |name| {
+              let script = if name == task_name {
+                entry_script.clone()
+              } else {
+                tasks_config.get(name).unwrap().clone()
+              };
+              run_deno_task_script(name, script, &cwd)
+            },
+          ))
+          .await;
+          for exit_code in results {
+            let exit_code = exit_code?;
+            if exit_code != 0 {
+              return Ok(exit_code);
+            }
+          }
+        }
+        Ok(0)
+      })
+      .await?;
     Ok(exit_code)
   } else if package_json_scripts.contains_key(task_name) {
     let package_json_deps_provider = factory.package_json_deps_provider();
@@ -104,6 +171,7 @@ pub async fn execute_script(
     // At this point we already checked if the task name exists in package.json.
     // We can therefore check for "pre" and "post" scripts too, since we're only
     // dealing with package.json here and not deno.json
+    let resolved_task_name = task_name.clone();
     let task_names = vec![
       format!("pre{}", task_name),
       task_name.clone(),
@@ -111,7 +179,14 @@ pub async fn execute_script(
     ];
     for task_name in task_names {
       if let Some(script) = package_json_scripts.get(&task_name) {
-        let script = get_script_with_args(script, cli_options);
+        let script = if !alias_args.is_empty()
+          && task_name == resolved_task_name
+        {
+          format!("{script} {alias_args}")
+        } else {
+          script.clone()
+        };
+        let script = get_script_with_args(&script, cli_options);
         output_task(&task_name, &script);
         let seq_list = deno_task_shell::parser::parse(&script)
           .with_context(|| format!("Error parsing script '{task_name}'."))?;
@@ -138,11 +213,231 @@ pub async fn execute_script(
     Ok(0)
   } else {
     eprintln!("Task not found: {task_name}");
+    if let Some(suggestion) =
+      suggest_task_name(task_name, &tasks_config, &package_json_scripts)
+    {
+      eprintln!("  Did you mean '{}'?", suggestion);
+    }
     print_available_tasks(&tasks_config, &package_json_scripts);
     Ok(1)
   }
 }
 
+/// Finds the closest matching task name to `task_name` using the Levenshtein
+/// edit distance, returning `None` if nothing is close enough to be useful.
+fn suggest_task_name(
+  task_name: &str,
+  tasks_config: &IndexMap<String, String>,
+  package_json_scripts: &IndexMap<String, String>,
+) -> Option<String> {
+  let max_distance = std::cmp::max(task_name.chars().count() / 3, 1);
+  // (distance, common_prefix_len, name)
+  let mut best: Option<(usize, usize, String)> = None;
+  for candidate in tasks_config.keys().chain(package_json_scripts.keys()) {
+    let distance = levenshtein_distance(task_name, candidate);
+    if distance > max_distance {
+      continue;
+    }
+    let prefix_len = task_name
+      .chars()
+      .zip(candidate.chars())
+      .take_while(|(a, b)| a == b)
+      .count();
+    let is_better = match &best {
+      Some((best_distance, best_prefix_len, _)) => {
+        distance < *best_distance
+          || (distance == *best_distance && prefix_len > *best_prefix_len)
+      }
+      None => true,
+    };
+    if is_better {
+      best = Some((distance, prefix_len, candidate.clone()));
+    }
+  }
+  best.map(|(_, _, name)| name)
+}
+
+/// Computes the Levenshtein (edit) distance between two strings using the
+/// standard two-row dynamic programming approach.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+  let a = a.chars().collect::<Vec<_>>();
+  let b = b.chars().collect::<Vec<_>>();
+  let mut prev_row = (0..=b.len()).collect::<Vec<_>>();
+  let mut cur_row = vec![0; b.len() + 1];
+  for (i, a_char) in a.iter().enumerate() {
+    cur_row[0] = i + 1;
+    for (j, b_char) in b.iter().enumerate() {
+      let substitution_cost = if a_char == b_char { 0 } else { 1 };
+      cur_row[j + 1] = std::cmp::min(
+        std::cmp::min(cur_row[j] + 1, prev_row[j + 1] + 1),
+        prev_row[j] + substitution_cost,
+      );
+    }
+    std::mem::swap(&mut prev_row, &mut cur_row);
+  }
+  prev_row[b.len()]
+}
+
+/// Resolves `task_name` against the `aliases` map from `deno.json`, the way
+/// cargo resolves `[alias]` entries: an alias value like `"test --allow-net"`
+/// splits into the real task name (`test`) and preset arguments
+/// (`--allow-net`), which are appended after the real task's script (e.g.
+/// `deno test --allow-net`), the same way `cargo test --release` appends
+/// after `cargo test`. Aliases may chain to other aliases, but a cycle or
+/// an alias that shadows a real task name — at any point in the chain — is
+/// an error. Returns `Ok(None)` if `task_name` isn't an alias.
+fn resolve_task_alias(
+  task_name: &str,
+  aliases: &IndexMap<String, String>,
+  tasks_config: &IndexMap<String, String>,
+) -> Result<Option<(String, String)>, AnyError> {
+  if !aliases.contains_key(task_name) {
+    return Ok(None);
+  }
+  if tasks_config.contains_key(task_name) {
+    bail!(
+      "Alias '{}' has the same name as an existing task and cannot be used.",
+      task_name
+    );
+  }
+  let mut visited = vec![task_name.to_string()];
+  let mut preset_args = Vec::new();
+  let mut current = task_name.to_string();
+  loop {
+    let aliased_command = aliases.get(&current).unwrap();
+    let (target, args) = match aliased_command.split_once(' ') {
+      Some((target, args)) => (target.to_string(), args.trim().to_string()),
+      None => (aliased_command.clone(), String::new()),
+    };
+    if !args.is_empty() {
+      preset_args.push(args);
+    }
+    if !aliases.contains_key(&target) {
+      return Ok(Some((target, preset_args.join(" "))));
+    }
+    if tasks_config.contains_key(&target) {
+      bail!(
+        "Alias '{}' has the same name as an existing task and cannot be used.",
+        target
+      );
+    }
+    if visited.contains(&target) {
+      visited.push(target);
+      bail!("Alias cycle detected: {}", visited.join(" -> "));
+    }
+    visited.push(target.clone());
+    current = target;
+  }
+}
+
+/// Parses and executes a single resolved task script, printing the `Task`
+/// banner line first the way `execute_script` always has.
+async fn run_deno_task_script(
+  task_name: &str,
+  script: String,
+  cwd: &Path,
+) -> Result<i32, AnyError> {
+  output_task(task_name, &script);
+  let seq_list = deno_task_shell::parser::parse(&script)
+    .with_context(|| format!("Error parsing script '{task_name}'."))?;
+  let env_vars = collect_env_vars();
+  let exit_code =
+    deno_task_shell::execute(seq_list, env_vars, cwd, Default::default())
+      .await;
+  Ok(exit_code)
+}
+
+/// Topologically sorts `task_name`'s `"dependencies"` declared in
+/// `deno.json`, building a DAG over the configured tasks. The returned list
+/// contains each dependency at most once and always ends with `task_name`
+/// itself. Errors with the offending path if a dependency cycle is found.
+fn resolve_task_execution_order(
+  task_name: &str,
+  tasks_config: &IndexMap<String, String>,
+  task_dependencies: &IndexMap<String, Vec<String>>,
+) -> Result<Vec<String>, AnyError> {
+  let mut order = Vec::new();
+  let mut visited = std::collections::HashSet::new();
+  let mut visiting = Vec::new();
+  visit_task_dependency(
+    task_name,
+    tasks_config,
+    task_dependencies,
+    &mut visited,
+    &mut visiting,
+    &mut order,
+  )?;
+  Ok(order)
+}
+
+fn visit_task_dependency(
+  name: &str,
+  tasks_config: &IndexMap<String, String>,
+  task_dependencies: &IndexMap<String, Vec<String>>,
+  visited: &mut std::collections::HashSet<String>,
+  visiting: &mut Vec<String>,
+  order: &mut Vec<String>,
+) -> Result<(), AnyError> {
+  if visited.contains(name) {
+    return Ok(());
+  }
+  if !tasks_config.contains_key(name) {
+    bail!("Could not find task '{}' referenced as a dependency.", name);
+  }
+  if let Some(pos) = visiting.iter().position(|n| n == name) {
+    let mut cycle_path = visiting[pos..].to_vec();
+    cycle_path.push(name.to_string());
+    bail!("Task dependency cycle detected: {}", cycle_path.join(" -> "));
+  }
+  visiting.push(name.to_string());
+  if let Some(deps) = task_dependencies.get(name) {
+    for dep in deps {
+      visit_task_dependency(
+        dep,
+        tasks_config,
+        task_dependencies,
+        visited,
+        visiting,
+        order,
+      )?;
+    }
+  }
+  visiting.pop();
+  visited.insert(name.to_string());
+  order.push(name.to_string());
+  Ok(())
+}
+
+/// Groups an already topologically-sorted task list into levels that can
+/// run concurrently under `--parallel`: a task's level is one past the
+/// deepest level of any of its dependencies, so every task in a level only
+/// depends on tasks in earlier levels.
+fn group_into_levels(
+  order: &[String],
+  task_dependencies: &IndexMap<String, Vec<String>>,
+) -> Vec<Vec<String>> {
+  let mut depths: HashMap<&str, usize> = HashMap::new();
+  for name in order {
+    let depth = task_dependencies
+      .get(name)
+      .map(|deps| {
+        deps
+          .iter()
+          .map(|dep| depths.get(dep.as_str()).copied().unwrap_or(0) + 1)
+          .max()
+          .unwrap_or(0)
+      })
+      .unwrap_or(0);
+    depths.insert(name, depth);
+  }
+  let level_count = depths.values().copied().max().unwrap_or(0) + 1;
+  let mut levels = vec![Vec::new(); level_count];
+  for name in order {
+    levels[depths[name.as_str()]].push(name.clone());
+  }
+  levels
+}
+
 fn get_script_with_args(script: &str, options: &CliOptions) -> String {
   let additional_args = options
     .argv()
@@ -240,6 +535,154 @@ fn print_available_tasks(
   }
 }
 
+/// Implements `deno task info [name]`: a non-executing diagnostic mode,
+/// modeled after `tauri info`, that reports the fully resolved execution
+/// environment for a task (or, with no task name, for the project as a
+/// whole) instead of running anything.
+async fn print_task_info(
+  factory: &CliFactory,
+  cli_options: &CliOptions,
+  tasks_config: &IndexMap<String, String>,
+  package_json_scripts: &IndexMap<String, String>,
+  // the resolved task name (after alias resolution) and its alias preset args
+  task: Option<(String, String)>,
+  cwd_flag: Option<&str>,
+) -> Result<i32, AnyError> {
+  match task {
+    Some((task_name, alias_args)) => {
+      let task_name = task_name.as_str();
+      let is_deno_task = tasks_config.contains_key(task_name);
+      let raw_script = if is_deno_task {
+        tasks_config.get(task_name)
+      } else {
+        package_json_scripts.get(task_name)
+      };
+      // check the task actually exists before paying for npm resolver setup
+      let Some(raw_script) = raw_script else {
+        eprintln!("Task not found: {task_name}");
+        return Ok(1);
+      };
+      let script = if alias_args.is_empty() {
+        raw_script.clone()
+      } else {
+        format!("{raw_script} {alias_args}")
+      };
+      let resolved_script = get_script_with_args(&script, cli_options);
+      let cwd = if let Some(path) = cwd_flag {
+        canonicalize_path(&PathBuf::from(path))?
+      } else if is_deno_task {
+        let config_file_url =
+          cli_options.maybe_config_file_specifier().unwrap();
+        config_file_url
+          .to_file_path()
+          .unwrap()
+          .parent()
+          .unwrap()
+          .to_owned()
+      } else {
+        cli_options
+          .maybe_package_json()
+          .as_ref()
+          .unwrap()
+          .path
+          .parent()
+          .unwrap()
+          .to_owned()
+      };
+
+      println!("{} {}", colors::green("Task:"), colors::cyan(task_name));
+      println!("Raw script: {raw_script}");
+      println!("Resolved script: {resolved_script}");
+      println!("Cwd: {}", cwd.display());
+
+      // deno.json tasks are run via `run_deno_task_script`, which never
+      // resolves npm bin commands or adds node_modules/.bin to PATH, so
+      // only show that table for package.json tasks, where it's real.
+      if is_deno_task {
+        println!("PATH addition: (none)");
+        println!("npm bin commands: (none, not resolved for deno.json tasks)");
+      } else {
+        let npm_resolver = factory.npm_resolver().await?;
+        let node_resolver = factory.node_resolver().await?;
+        let npm_command_versions = match npm_resolver.as_managed() {
+          Some(npm_resolver) => {
+            resolve_npm_command_versions(npm_resolver, node_resolver)?
+          }
+          None => Default::default(),
+        };
+        print_node_modules_bin_path(npm_resolver.root_node_modules_path());
+        print_npm_command_versions(&npm_command_versions);
+      }
+    }
+    None => {
+      let npm_resolver = factory.npm_resolver().await?;
+      let node_resolver = factory.node_resolver().await?;
+      let npm_command_versions = match npm_resolver.as_managed() {
+        Some(npm_resolver) => {
+          resolve_npm_command_versions(npm_resolver, node_resolver)?
+        }
+        None => Default::default(),
+      };
+
+      println!("{}", colors::green("Task execution environment (global):"));
+      println!(
+        "Managed npm resolver: {}",
+        npm_resolver.as_managed().is_some()
+      );
+      print_node_modules_bin_path(npm_resolver.root_node_modules_path());
+      print_npm_command_versions(&npm_command_versions);
+      if let Some(npm_resolver) = npm_resolver.as_managed() {
+        println!("Top-level packages:");
+        for id in npm_resolver.snapshot().top_level_packages() {
+          println!("  - {}", id.nv);
+        }
+      }
+    }
+  }
+
+  Ok(0)
+}
+
+fn print_node_modules_bin_path(node_modules_path: Option<&Path>) {
+  match node_modules_path {
+    Some(dir_path) => {
+      println!("PATH addition: {}", dir_path.join(".bin").display())
+    }
+    None => println!("PATH addition: (none)"),
+  }
+}
+
+fn print_npm_command_versions(
+  npm_command_versions: &IndexMap<String, PackageNv>,
+) {
+  println!("npm bin commands:");
+  if npm_command_versions.is_empty() {
+    println!("  (none)");
+  }
+  for (bin_command, npm_package) in npm_command_versions {
+    println!("  - {bin_command} -> {npm_package}");
+  }
+}
+
+/// Like `resolve_npm_commands`, but for `deno task info` purposes we want
+/// the backing `PackageNv` for display rather than a `ShellCommand` to run.
+fn resolve_npm_command_versions(
+  npm_resolver: &ManagedCliNpmResolver,
+  node_resolver: &NodeResolver,
+) -> Result<IndexMap<String, PackageNv>, AnyError> {
+  let mut result = IndexMap::new();
+  let snapshot = npm_resolver.snapshot();
+  for id in snapshot.top_level_packages() {
+    let package_folder = npm_resolver.resolve_pkg_folder_from_pkg_id(id)?;
+    let bin_commands =
+      node_resolver.resolve_binary_commands(&package_folder)?;
+    for bin_command in bin_commands {
+      result.insert(bin_command, id.nv.clone());
+    }
+  }
+  Ok(result)
+}
+
 struct NpxCommand;
 
 impl ShellCommand for NpxCommand {
@@ -324,6 +767,198 @@ fn resolve_npm_commands(
 mod test {
   use super::*;
 
+  #[test]
+  fn test_levenshtein_distance() {
+    assert_eq!(levenshtein_distance("", ""), 0);
+    assert_eq!(levenshtein_distance("build", "build"), 0);
+    assert_eq!(levenshtein_distance("buidl", "build"), 2);
+    assert_eq!(levenshtein_distance("built", "build"), 1);
+    assert_eq!(levenshtein_distance("test", "build"), 5);
+  }
+
+  #[test]
+  fn test_suggest_task_name() {
+    let tasks_config = IndexMap::from([
+      ("build".to_string(), "deno run build.ts".to_string()),
+      ("bundle".to_string(), "deno run bundle.ts".to_string()),
+    ]);
+    let package_json_scripts = IndexMap::from([
+      ("test".to_string(), "node test.js".to_string()),
+    ]);
+    assert_eq!(
+      suggest_task_name("buld", &tasks_config, &package_json_scripts),
+      Some("build".to_string())
+    );
+    assert_eq!(
+      suggest_task_name("tst", &tasks_config, &package_json_scripts),
+      Some("test".to_string())
+    );
+    assert_eq!(
+      suggest_task_name(
+        "completely-unrelated-name",
+        &tasks_config,
+        &package_json_scripts
+      ),
+      None
+    );
+  }
+
+  #[test]
+  fn test_resolve_task_alias() {
+    let tasks_config = IndexMap::from([
+      ("test".to_string(), "deno test".to_string()),
+      ("build".to_string(), "deno run build.ts".to_string()),
+    ]);
+
+    let aliases =
+      IndexMap::from([("t".to_string(), "test --allow-net".to_string())]);
+    assert_eq!(
+      resolve_task_alias("t", &aliases, &tasks_config).unwrap(),
+      Some(("test".to_string(), "--allow-net".to_string()))
+    );
+    assert_eq!(
+      resolve_task_alias("test", &aliases, &tasks_config).unwrap(),
+      None
+    );
+
+    // chained aliases accumulate their preset args
+    let chained_aliases = IndexMap::from([
+      ("t".to_string(), "tn".to_string()),
+      ("tn".to_string(), "test --allow-net".to_string()),
+    ]);
+    assert_eq!(
+      resolve_task_alias("t", &chained_aliases, &tasks_config).unwrap(),
+      Some(("test".to_string(), "--allow-net".to_string()))
+    );
+
+    // a cycle is an error
+    let cyclic_aliases = IndexMap::from([
+      ("a".to_string(), "b".to_string()),
+      ("b".to_string(), "a".to_string()),
+    ]);
+    assert!(resolve_task_alias("a", &cyclic_aliases, &tasks_config).is_err());
+
+    // shadowing a real task name is an error
+    let shadowing_aliases =
+      IndexMap::from([("build".to_string(), "test".to_string())]);
+    assert!(
+      resolve_task_alias("build", &shadowing_aliases, &tasks_config).is_err()
+    );
+
+    // shadowing is also an error when it happens partway through a chain,
+    // not just when the shadowing alias is the one directly invoked
+    let shadowing_chain_aliases = IndexMap::from([
+      ("x".to_string(), "build".to_string()),
+      ("build".to_string(), "test".to_string()),
+    ]);
+    assert!(
+      resolve_task_alias("build", &shadowing_chain_aliases, &tasks_config)
+        .is_err()
+    );
+    assert!(
+      resolve_task_alias("x", &shadowing_chain_aliases, &tasks_config)
+        .is_err()
+    );
+  }
+
+  /// Pins the `format!("{script} {alias_args}")` form that `execute_script`
+  /// uses for an aliased task (restated here, not called through
+  /// `execute_script`): the alias's preset args go after the resolved
+  /// script, matching cargo's `cargo test --release` ordering, rather than
+  /// before it (which would make the shell try to run the first flag as a
+  /// program).
+  #[test]
+  fn test_alias_script_assembly_order() {
+    let script = "deno test";
+    let alias_args = "--allow-net";
+    let composed = format!("{script} {alias_args}");
+    assert_eq!(composed, "deno test --allow-net");
+  }
+
+  #[test]
+  fn test_resolve_task_execution_order() {
+    let tasks_config = IndexMap::from([
+      ("codegen".to_string(), "deno run codegen.ts".to_string()),
+      ("build".to_string(), "deno run build.ts".to_string()),
+      ("test".to_string(), "deno test".to_string()),
+    ]);
+    let task_dependencies = IndexMap::from([
+      ("build".to_string(), vec!["codegen".to_string()]),
+      (
+        "test".to_string(),
+        vec!["build".to_string(), "codegen".to_string()],
+      ),
+    ]);
+    assert_eq!(
+      resolve_task_execution_order("test", &tasks_config, &task_dependencies)
+        .unwrap(),
+      vec!["codegen".to_string(), "build".to_string(), "test".to_string()]
+    );
+    assert_eq!(
+      resolve_task_execution_order(
+        "codegen",
+        &tasks_config,
+        &task_dependencies
+      )
+      .unwrap(),
+      vec!["codegen".to_string()]
+    );
+
+    let cyclic_dependencies = IndexMap::from([
+      ("a".to_string(), vec!["b".to_string()]),
+      ("b".to_string(), vec!["a".to_string()]),
+    ]);
+    let cyclic_tasks_config = IndexMap::from([
+      ("a".to_string(), "echo a".to_string()),
+      ("b".to_string(), "echo b".to_string()),
+    ]);
+    assert!(resolve_task_execution_order(
+      "a",
+      &cyclic_tasks_config,
+      &cyclic_dependencies
+    )
+    .is_err());
+
+    // a dependency that isn't a real task is an error, not a panic
+    let missing_dependencies = IndexMap::from([(
+      "test".to_string(),
+      vec!["no-such-task".to_string()],
+    )]);
+    assert!(resolve_task_execution_order(
+      "test",
+      &tasks_config,
+      &missing_dependencies
+    )
+    .is_err());
+  }
+
+  #[test]
+  fn test_group_into_levels() {
+    let tasks_config = IndexMap::from([
+      ("codegen".to_string(), "deno run codegen.ts".to_string()),
+      ("build".to_string(), "deno run build.ts".to_string()),
+      ("test".to_string(), "deno test".to_string()),
+    ]);
+    let task_dependencies = IndexMap::from([
+      ("build".to_string(), vec!["codegen".to_string()]),
+      (
+        "test".to_string(),
+        vec!["build".to_string(), "codegen".to_string()],
+      ),
+    ]);
+    let order =
+      resolve_task_execution_order("test", &tasks_config, &task_dependencies)
+        .unwrap();
+    assert_eq!(
+      group_into_levels(&order, &task_dependencies),
+      vec![
+        vec!["codegen".to_string()],
+        vec!["build".to_string()],
+        vec!["test".to_string()],
+      ]
+    );
+  }
+
   #[test]
   fn test_prepend_to_path() {
     let mut env_vars = HashMap::new();